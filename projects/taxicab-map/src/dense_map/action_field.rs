@@ -1,12 +1,17 @@
 use super::*;
-use std::vec::IntoIter;
+use crate::ordered_cost::OrderedCost;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    vec::IntoIter,
+};
 
 pub struct ActionFieldSolver<'a, T> {
     map: &'a TaxicabMap<T>,
-    open: BTreeMap<Point, f64>,
-    close: BTreeMap<Point, f64>,
-    passable: Box<dyn Fn(&Point, &T) -> bool>,
-    action_cost: Box<dyn Fn(&Point, &T) -> f64>,
+    open: BTreeMap<(isize, isize), f64>,
+    close: BTreeMap<(isize, isize), f64>,
+    passable: Box<dyn Fn(&(isize, isize), &T) -> bool>,
+    action_cost: Box<dyn Fn(&(isize, isize), &T) -> f64>,
     action_points: f64,
 }
 
@@ -24,7 +29,7 @@ impl<T> TaxicabMap<T> {
     /// ```
     /// # use hexagon_map::HexagonMap;
     /// ```
-    pub fn action_field(&self, start: Point, action: f64) -> ActionFieldSolver<T> {
+    pub fn action_field(&self, start: (isize, isize), action: f64) -> ActionFieldSolver<T> {
         let mut open = BTreeMap::new();
         open.insert(start, 0.0);
         ActionFieldSolver {
@@ -41,14 +46,14 @@ impl<T> TaxicabMap<T> {
 impl<'a, T> ActionFieldSolver<'a, T> {
     pub fn with_passable<F>(mut self, passable: F) -> Self
     where
-        F: Fn(&Point, &T) -> bool + 'static,
+        F: Fn(&(isize, isize), &T) -> bool + 'static,
     {
         self.passable = Box::new(passable);
         self
     }
     pub fn with_cost<F>(mut self, cost: F) -> Self
     where
-        F: Fn(&Point, &T) -> f64 + 'static,
+        F: Fn(&(isize, isize), &T) -> f64 + 'static,
     {
         self.action_cost = Box::new(cost);
         self
@@ -56,33 +61,52 @@ impl<'a, T> ActionFieldSolver<'a, T> {
 }
 
 impl<'a, T> ActionFieldSolver<'a, T> {
-    /// Get all passable neighbors from a direction
-    pub fn neighbors(&self, point: &Point) -> Vec<(Point, f64)> {
-        let mut neighbors = Vec::with_capacity(6);
+    /// Get all passable, defined neighbors of a point that are not yet closed.
+    pub fn neighbors(&self, point: &(isize, isize)) -> Vec<((isize, isize), f64)> {
+        let mut neighbors = Vec::with_capacity(4);
         for direction in Direction::all() {
-            let key = point.go(direction);
-            if let Some(value) = self.map.get_point(key) {
+            let (dx, dy) = direction_offset(direction);
+            let key = (point.0 + dx, point.1 + dy);
+            if let Some(value) = self.map.get_point(key.0, key.1) {
                 if !(self.passable)(&key, value) {
                     continue;
                 }
                 if self.close.contains_key(&key) {
                     continue;
                 }
-                let cost = (self.action_cost)(point, value);
+                let cost = (self.action_cost)(&key, value);
                 neighbors.push((key, cost));
             }
         }
         neighbors
     }
-    pub fn solve(mut self) -> impl Iterator<Item = (f64, Point)> {
-        while let Some((point, cost)) = self.open.pop_first() {
+    /// Expand the frontier in cost order (Dijkstra-style uniform-cost search), bounded by
+    /// `action_points`.
+    ///
+    /// The frontier is a min-heap keyed by accumulated cost rather than the `BTreeMap`'s point
+    /// order, so the first time a point is popped it is guaranteed to carry its cheapest cost.
+    /// `best` tracks the cheapest cost seen for every point; a point is only pushed again when a
+    /// strictly cheaper cost is found, and a pop whose cost is stale relative to `best` is
+    /// skipped (lazy deletion) instead of being expanded twice.
+    pub fn solve(mut self) -> impl Iterator<Item = (f64, (isize, isize))> {
+        let mut best = BTreeMap::new();
+        let mut heap = BinaryHeap::new();
+        for (&point, &cost) in self.open.iter() {
+            best.insert(point, cost);
+            heap.push(Reverse((OrderedCost(cost), point)));
+        }
+        while let Some(Reverse((OrderedCost(cost), point))) = heap.pop() {
+            if cost > *best.get(&point).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
             for (neighbor, neighbor_cost) in self.neighbors(&point) {
                 let new_cost = cost + neighbor_cost;
                 if new_cost > self.action_points {
                     continue;
                 }
-                else {
-                    self.open.insert(neighbor, new_cost);
+                if new_cost < *best.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                    best.insert(neighbor, new_cost);
+                    heap.push(Reverse((OrderedCost(new_cost), neighbor)));
                 }
             }
             self.close.insert(point, cost);
@@ -92,9 +116,31 @@ impl<'a, T> ActionFieldSolver<'a, T> {
 }
 
 impl<'a, T> IntoIterator for ActionFieldSolver<'a, T> {
-    type Item = (f64, Point);
+    type Item = (f64, (isize, isize));
     type IntoIter = IntoIter<Self::Item>;
     fn into_iter(self) -> Self::IntoIter {
         self.solve().collect_vec().into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_dependent_cost_charges_the_neighbor_not_the_source() {
+        let map = TaxicabMap::rectangle(3, 1, &());
+        // Stepping onto (1, 0) is free, every other step costs 1. If the cost were (wrongly)
+        // looked up by the source position instead of the neighbor, the 0 -> 1 step would be
+        // charged by source (0, 0) (not free) and never clear a 0.5 budget at all.
+        let reached: BTreeMap<_, _> = map
+            .action_field((0, 0), 0.5)
+            .with_cost(|pos, _| if *pos == (1, 0) { 0.0 } else { 1.0 })
+            .solve()
+            .map(|(cost, point)| (point, cost))
+            .collect();
+        assert_eq!(reached.get(&(0, 0)), Some(&0.0));
+        assert_eq!(reached.get(&(1, 0)), Some(&0.0));
+        assert!(!reached.contains_key(&(2, 0)));
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+impl<T: Clone + PartialEq + Default> TaxicabMap<T> {
+    /// Compute one generation of a cellular automaton over the whole grid.
+    ///
+    /// `rule` receives a cell's current value together with its existing orthogonal neighbors
+    /// (each paired with the [`Direction`] of the step from the cell to it) and returns the cell's next
+    /// value. Neighbors are read from the grid before this generation's update, so cells never
+    /// see already-updated neighbors.
+    ///
+    /// Cells not equal to `T::default()` are considered active. On a non-cyclic axis, every
+    /// boundary side that has an active cell sitting on it is grown outward by one row or column
+    /// before stepping (reusing [`extend`](Self::extend)), so patterns that spread outward are
+    /// never clipped. On a cyclic axis neighbors simply wrap instead.
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(&T, &[(&T, Direction)]) -> T,
+    {
+        self.grow_active_borders();
+        let (w, h) = self.get_size();
+        let next = Array2::from_shape_fn((w, h), |(i, j)| {
+            let (x, y) = relative_to_absolute(i, j, self.origin_x, self.origin_y);
+            let here = &self.dense[[i, j]];
+            let mut around = Vec::with_capacity(4);
+            for direction in Direction::all() {
+                let (dx, dy) = direction_offset(direction);
+                if let Some(value) = self.get_point(x + dx, y + dy) {
+                    around.push((value, direction));
+                }
+            }
+            rule(here, &around)
+        });
+        self.dense = next;
+    }
+
+    /// Grow the grid by one ring on every non-cyclic side that has an active cell sitting on it.
+    fn grow_active_borders(&mut self) {
+        let blank = T::default();
+        if !self.cycle_x {
+            let (_, h) = self.get_size();
+            if (0..h).any(|j| self.dense[[0, j]] != blank) {
+                self.extend(Direction::X(true), 1, &blank);
+                self.shift_origin(-1, 0);
+            }
+            let (w, h) = self.get_size();
+            if (0..h).any(|j| self.dense[[w - 1, j]] != blank) {
+                self.extend(Direction::X(false), 1, &blank);
+            }
+        }
+        if !self.cycle_y {
+            let (w, h) = self.get_size();
+            if (0..w).any(|i| self.dense[[i, 0]] != blank) {
+                self.extend(Direction::Y(true), 1, &blank);
+                self.shift_origin(0, -1);
+            }
+            let (w, h) = self.get_size();
+            if (0..w).any(|i| self.dense[[i, h - 1]] != blank) {
+                self.extend(Direction::Y(false), 1, &blank);
+            }
+        }
+    }
+}
+
+impl TaxicabMap<bool> {
+    /// Step one generation using the classic birth/survival rule, adapted to the taxicab
+    /// (orthogonal, 4-neighbor) neighborhood: a live cell with 2 or 3 live neighbors survives,
+    /// and a dead cell with exactly 3 live neighbors is born.
+    pub fn step_life(&mut self) {
+        self.step(|alive, neighbors| {
+            let living = neighbors.iter().filter(|(value, _)| **value).count();
+            matches!((*alive, living), (true, 2) | (true, 3) | (false, 3))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_grows_non_cyclic_border_when_active_cell_touches_edge() {
+        let mut map = TaxicabMap::rectangle(3, 3, &false);
+        map.set_point(0, 1, true);
+        map.step(|here, _| *here);
+        assert_eq!(map.get_size(), (4, 3));
+        assert_eq!(map.get_point(0, 1), Some(&true));
+    }
+
+    #[test]
+    fn step_life_kills_an_isolated_cell() {
+        let mut map = TaxicabMap::rectangle(3, 3, &false).with_cycle(true, true);
+        map.set_point(1, 1, true);
+        map.step_life();
+        assert_eq!(map.get_point(1, 1), Some(&false));
+    }
+}
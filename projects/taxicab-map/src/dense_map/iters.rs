@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::BTreeSet;
 
 impl<'i, T> IntoIterator for &'i TaxicabMap<T> {
     type Item = (isize, isize, &'i T);
@@ -56,6 +57,97 @@ impl<T> TaxicabMap<T> {
     }
 }
 
+/// A view over the points inside an absolute coordinate window, see [`TaxicabMap::points_in_rect`].
+pub struct GetTaxicabPointsInRect<'i, T> {
+    map: &'i TaxicabMap<T>,
+    cartesian: Product<Range<isize>, Range<isize>>,
+    visited: BTreeSet<(usize, usize)>,
+}
+
+/// A mutable view over the points inside an absolute coordinate window, see
+/// [`TaxicabMap::points_in_rect_mut`].
+pub struct MutGetTaxicabPointsInRect<'i, T> {
+    map: &'i mut TaxicabMap<T>,
+    cartesian: Product<Range<isize>, Range<isize>>,
+    visited: BTreeSet<(usize, usize)>,
+}
+
+impl<'i, T> Iterator for GetTaxicabPointsInRect<'i, T> {
+    type Item = (isize, isize, &'i T);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (x, y) = self.cartesian.next()?;
+            let (w, h) = self.map.get_isize();
+            let relative = absolute_to_relative(x, y, self.map.origin_x, self.map.origin_y, w, h, self.map.cycle_x, self.map.cycle_y);
+            let Some((i, j)) = relative else { continue };
+            // A cyclic axis can map distinct (x, y) in the requested window onto the same cell;
+            // only yield each underlying cell once.
+            if !self.visited.insert((i, j)) {
+                continue;
+            }
+            let v = self.map.dense.get((i, j))?;
+            return Some((x, y, v));
+        }
+    }
+}
+
+impl<'i, T> Iterator for MutGetTaxicabPointsInRect<'i, T> {
+    type Item = (isize, isize, &'i mut T);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (x, y) = self.cartesian.next()?;
+            let (w, h) = self.map.get_isize();
+            let relative = absolute_to_relative(x, y, self.map.origin_x, self.map.origin_y, w, h, self.map.cycle_x, self.map.cycle_y);
+            let Some((i, j)) = relative else { continue };
+            // A cyclic axis can map distinct (x, y) in the requested window onto the same cell;
+            // `get_mut_ptr` must only ever be called once per cell, or two `&mut T` could alias.
+            if !self.visited.insert((i, j)) {
+                continue;
+            }
+            // SAFETY: see MutGetTaxicabPoints::next above; `visited` guarantees each (i, j) is
+            // only handed out once per iterator, so the returned references never alias.
+            let v = unsafe { &mut *self.map.dense.get_mut_ptr((i, j))? };
+            return Some((x, y, v));
+        }
+    }
+}
+
+/// Clamp an absolute-coordinate range to the map's extent. On a non-cyclic axis this clamps to
+/// `origin..origin + size`, the only in-range window. On a cyclic axis every point is in range
+/// (just possibly repeated), but a range wider than one period visits cells it's already seen;
+/// clamp it down to exactly one period so the iterator is bounded by the map's actual extent
+/// rather than by however wide a caller's range happens to be.
+fn clamp_axis(range: Range<isize>, cyclic: bool, origin: isize, size: isize) -> Range<isize> {
+    if cyclic {
+        if range.end - range.start > size { origin..origin + size } else { range }
+    }
+    else {
+        let lo = range.start.max(origin);
+        let hi = range.end.min(origin + size);
+        lo..hi.max(lo)
+    }
+}
+
+impl<T> TaxicabMap<T> {
+    /// Iterate over all defined points whose absolute coordinates fall inside `x` and `y`,
+    /// clamped to the map and respecting `cycle_x`/`cycle_y` the same way [`TaxicabMap::get_point`]
+    /// does. This is the bounded counterpart to [`TaxicabMap::points_all`] for scanning a viewport
+    /// of a large map.
+    pub fn points_in_rect(&self, x: Range<isize>, y: Range<isize>) -> GetTaxicabPointsInRect<'_, T> {
+        let (w, h) = self.get_isize();
+        let x = clamp_axis(x, self.cycle_x, self.origin_x, w);
+        let y = clamp_axis(y, self.cycle_y, self.origin_y, h);
+        GetTaxicabPointsInRect { map: self, cartesian: x.cartesian_product(y), visited: BTreeSet::new() }
+    }
+    /// Mutable counterpart to [`TaxicabMap::points_in_rect`].
+    pub fn points_in_rect_mut(&mut self, x: Range<isize>, y: Range<isize>) -> MutGetTaxicabPointsInRect<'_, T> {
+        let (w, h) = self.get_isize();
+        let x = clamp_axis(x, self.cycle_x, self.origin_x, w);
+        let y = clamp_axis(y, self.cycle_y, self.origin_y, h);
+        MutGetTaxicabPointsInRect { map: self, cartesian: x.cartesian_product(y), visited: BTreeSet::new() }
+    }
+}
+
 /// A diamond shaped area around a point.
 pub struct GetTaxicabPointsAround {
     points: DiamondPoints,
@@ -155,4 +247,32 @@ impl<T> TaxicabMap<T> {
             cycle_y: self.cycle_y,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_in_rect_stays_inside_non_cyclic_window() {
+        let map = TaxicabMap::rectangle(3, 3, &0);
+        let points: Vec<_> = map.points_in_rect(-5..5, -5..5).map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(points.len(), 9);
+        assert!(points.iter().all(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y)));
+    }
+
+    #[test]
+    fn points_in_rect_mut_yields_each_cyclic_cell_once() {
+        let mut map = TaxicabMap::rectangle(3, 3, &0).with_cycle(true, true);
+        // A window several periods wide must still hand out only one `&mut` per wrapped cell.
+        let mut seen = BTreeSet::new();
+        let mut count = 0;
+        for (x, y, value) in map.points_in_rect_mut(-10..10, -10..10) {
+            *value += 1;
+            assert!(seen.insert((x.rem_euclid(3), y.rem_euclid(3))));
+            count += 1;
+        }
+        assert_eq!(count, 9);
+        assert!(map.points_all().all(|(_, _, v)| *v == 1));
+    }
 }
\ No newline at end of file
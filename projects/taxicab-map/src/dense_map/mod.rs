@@ -3,16 +3,20 @@ use itertools::{Itertools, Product};
 use ndarray::Array2;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     mem::swap,
     ops::{Index, IndexMut, Range},
 };
 
-// pub mod action_field;
+pub mod action_field;
+mod automaton;
 // pub mod path_finder;
 mod indexes;
 pub mod iters;
+mod region;
 
-/// A dense manhattan map, if your map size will grow, or most areas will be blank, this is a better choice.
+/// A dense manhattan map, backed by a full `Array2`. Best when most of the map will be filled;
+/// if most areas will be blank, use [`SparseTaxicabMap`](crate::SparseTaxicabMap) instead.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct TaxicabMap<T> {
     dense: Array2<T>,
@@ -64,6 +68,15 @@ impl<T: Clone> TaxicabMap<T> {
         }
         self.dense = new;
     }
+    /// Convert to a [`SparseTaxicabMap`](crate::SparseTaxicabMap), keeping every cell since a
+    /// dense map has none left undefined.
+    pub fn to_sparse(&self) -> crate::SparseTaxicabMap<T> {
+        let mut sparse = BTreeMap::new();
+        for (x, y, value) in self.points_all() {
+            sparse.insert((x, y), value.clone());
+        }
+        crate::SparseTaxicabMap::from_parts(sparse)
+    }
 }
 
 impl<T> TaxicabMap<T> {
@@ -174,3 +187,14 @@ pub(crate) fn absolute_to_relative(
 pub(crate) fn relative_to_absolute(x: usize, y: usize, origin_x: isize, origin_y: isize) -> (isize, isize) {
     (x as isize + origin_x, y as isize + origin_y)
 }
+
+/// The unit offset of an orthogonal step in a given [`Direction`].
+#[inline]
+pub(crate) fn direction_offset(direction: Direction) -> (isize, isize) {
+    match direction {
+        Direction::X(true) => (1, 0),
+        Direction::X(false) => (-1, 0),
+        Direction::Y(true) => (0, 1),
+        Direction::Y(false) => (0, -1),
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use std::collections::{BTreeSet, VecDeque};
+
+impl<T> TaxicabMap<T> {
+    /// Canonicalize an absolute coordinate to the representative absolute position of the cell
+    /// it wraps to, so that on a cyclic axis every physical cell has exactly one key. Returns
+    /// `None` if the coordinate is off the map on a non-cyclic axis.
+    fn canonical_point(&self, x: isize, y: isize) -> Option<(isize, isize)> {
+        let (w, h) = self.get_isize();
+        let (i, j) = absolute_to_relative(x, y, self.origin_x, self.origin_y, w, h, self.cycle_x, self.cycle_y)?;
+        Some(relative_to_absolute(i, j, self.origin_x, self.origin_y))
+    }
+
+    /// Flood fill outward from `start`, collecting every cell reachable through orthogonal,
+    /// `passable` neighbors (BFS), respecting cyclic wrap on `cycle_x`/`cycle_y`.
+    ///
+    /// Coordinates are canonicalized to their cell's representative position before being
+    /// recorded, so on a cyclic axis wrapped duplicates collapse onto one key and the fill is
+    /// bounded by the map's period instead of marching off to infinity.
+    ///
+    /// Returns an empty `Vec` if `start` is undefined or not itself passable.
+    pub fn flood_fill(&self, start: (isize, isize), passable: impl Fn(&(isize, isize), &T) -> bool) -> Vec<(isize, isize)> {
+        let mut visited = BTreeSet::new();
+        let mut frontier = VecDeque::new();
+        let mut region = Vec::new();
+        if let Some(start) = self.canonical_point(start.0, start.1)
+            && let Some(value) = self.get_point(start.0, start.1)
+            && passable(&start, value)
+        {
+            visited.insert(start);
+            frontier.push_back(start);
+        }
+        while let Some(point) = frontier.pop_front() {
+            region.push(point);
+            for direction in Direction::all() {
+                let (dx, dy) = direction_offset(direction);
+                let Some(neighbor) = self.canonical_point(point.0 + dx, point.1 + dy) else { continue };
+                if visited.contains(&neighbor) {
+                    continue;
+                }
+                if let Some(value) = self.get_point(neighbor.0, neighbor.1)
+                    && passable(&neighbor, value)
+                {
+                    visited.insert(neighbor);
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+        region
+    }
+
+    /// Label every connected component of `passable` cells in the map.
+    ///
+    /// Scans every cell once; each unvisited passable cell seeds a [`flood_fill`](Self::flood_fill)
+    /// that claims its whole component. Useful for room detection, reachable-area checks, or
+    /// island counting.
+    pub fn components(&self, passable: impl Fn(&(isize, isize), &T) -> bool) -> Vec<Vec<(isize, isize)>> {
+        let mut visited = BTreeSet::new();
+        let mut components = Vec::new();
+        for (x, y, value) in self.points_all() {
+            let point = (x, y);
+            if visited.contains(&point) || !passable(&point, value) {
+                continue;
+            }
+            let region = self.flood_fill(point, &passable);
+            visited.extend(region.iter().copied());
+            components.push(region);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_terminates_and_stays_bounded_on_cyclic_map() {
+        let map = TaxicabMap::rectangle(3, 3, &true).with_cycle(true, true);
+        let region = map.flood_fill((0, 0), |_, value| *value);
+        // A fully-passable cyclic map has exactly one component, sized to the map's period, not
+        // an ever-growing frontier that never revisits a wrapped coordinate.
+        assert_eq!(region.len(), 9);
+    }
+
+    #[test]
+    fn components_labels_disconnected_regions() {
+        let mut map = TaxicabMap::rectangle(5, 1, &false);
+        for x in [0isize, 2, 3] {
+            map.set_point(x, 0, true);
+        }
+        let components = map.components(|_, value| *value);
+        let mut sizes: Vec<_> = components.iter().map(Vec::len).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2]);
+    }
+}
@@ -5,14 +5,20 @@
 mod dense_map;
 mod direction;
 mod joint;
+mod ordered_cost;
 mod path_finder;
+mod sparse_map;
 
 pub use crate::{
     dense_map::{
-        iters::{DiamondPoints, GetTaxicabPoints, GetTaxicabPointsAround, MutGetTaxicabPoints},
+        iters::{
+            DiamondPoints, GetTaxicabPoints, GetTaxicabPointsAround, GetTaxicabPointsInRect, MutGetTaxicabPoints,
+            MutGetTaxicabPointsInRect,
+        },
         TaxicabMap,
     },
     direction::Direction,
     joint::Joint,
     path_finder::PathFinder,
+    sparse_map::SparseTaxicabMap,
 };
@@ -0,0 +1,23 @@
+use std::cmp::Ordering;
+
+/// A totally-ordered wrapper around a cost, so it can be used as a `BinaryHeap` key.
+///
+/// Shared by the dense and sparse `ActionFieldSolver`s. Costs produced by `action_cost` are
+/// never `NaN` in practice, so falling back to [`Ordering::Equal`] on an unexpected `NaN` is
+/// good enough here.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct OrderedCost(pub(crate) f64);
+
+impl Eq for OrderedCost {}
+
+impl PartialOrd for OrderedCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::{dense_map::direction_offset, ordered_cost::OrderedCost, Direction};
+use itertools::Itertools;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    vec::IntoIter,
+};
+
+pub struct ActionFieldSolver<'a, T> {
+    map: &'a SparseTaxicabMap<T>,
+    open: BTreeMap<(isize, isize), f64>,
+    close: BTreeMap<(isize, isize), f64>,
+    passable: Box<dyn Fn(&(isize, isize), &T) -> bool>,
+    action_cost: Box<dyn Fn(&(isize, isize), &T) -> f64>,
+    action_points: f64,
+}
+
+impl<T> SparseTaxicabMap<T> {
+    /// Set up an action-field solver rooted at `start`, bounded by `action` points.
+    pub fn action_field(&self, start: (isize, isize), action: f64) -> ActionFieldSolver<T> {
+        let mut open = BTreeMap::new();
+        open.insert(start, 0.0);
+        ActionFieldSolver {
+            map: self,
+            open,
+            close: Default::default(),
+            action_points: action,
+            passable: Box::new(|_, _| true),
+            action_cost: Box::new(|_, _| 0.0),
+        }
+    }
+}
+
+impl<'a, T> ActionFieldSolver<'a, T> {
+    pub fn with_passable<F>(mut self, passable: F) -> Self
+    where
+        F: Fn(&(isize, isize), &T) -> bool + 'static,
+    {
+        self.passable = Box::new(passable);
+        self
+    }
+    pub fn with_cost<F>(mut self, cost: F) -> Self
+    where
+        F: Fn(&(isize, isize), &T) -> f64 + 'static,
+    {
+        self.action_cost = Box::new(cost);
+        self
+    }
+}
+
+impl<'a, T> ActionFieldSolver<'a, T> {
+    /// Get all passable, defined neighbors of a point that are not yet closed.
+    pub fn neighbors(&self, point: &(isize, isize)) -> Vec<((isize, isize), f64)> {
+        let mut neighbors = Vec::with_capacity(4);
+        for direction in Direction::all() {
+            let (dx, dy) = direction_offset(direction);
+            let key = (point.0 + dx, point.1 + dy);
+            if let Some(value) = self.map.get_point(key.0, key.1) {
+                if !(self.passable)(&key, value) {
+                    continue;
+                }
+                if self.close.contains_key(&key) {
+                    continue;
+                }
+                let cost = (self.action_cost)(&key, value);
+                neighbors.push((key, cost));
+            }
+        }
+        neighbors
+    }
+    /// Expand the frontier in cost order (see [`crate::dense_map::action_field::ActionFieldSolver::solve`]
+    /// for the lazy-deletion Dijkstra scheme this mirrors).
+    pub fn solve(mut self) -> impl Iterator<Item = (f64, (isize, isize))> {
+        let mut best = BTreeMap::new();
+        let mut heap = BinaryHeap::new();
+        for (&point, &cost) in self.open.iter() {
+            best.insert(point, cost);
+            heap.push(Reverse((OrderedCost(cost), point)));
+        }
+        while let Some(Reverse((OrderedCost(cost), point))) = heap.pop() {
+            if cost > *best.get(&point).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
+            for (neighbor, neighbor_cost) in self.neighbors(&point) {
+                let new_cost = cost + neighbor_cost;
+                if new_cost > self.action_points {
+                    continue;
+                }
+                if new_cost < *best.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                    best.insert(neighbor, new_cost);
+                    heap.push(Reverse((OrderedCost(new_cost), neighbor)));
+                }
+            }
+            self.close.insert(point, cost);
+        }
+        self.close.iter().map(|(k, v)| (*v, *k)).sorted_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+    }
+}
+
+impl<'a, T> IntoIterator for ActionFieldSolver<'a, T> {
+    type Item = (f64, (isize, isize));
+    type IntoIter = IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.solve().collect_vec().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_minimal_cost_reachability_within_budget() {
+        let mut map = SparseTaxicabMap::new();
+        for (x, y) in [(0, 0), (1, 0), (2, 0), (0, 1), (0, 2)] {
+            map.set_point(x, y, ());
+        }
+        let reached: BTreeMap<_, _> =
+            map.action_field((0, 0), 2.0).with_cost(|_, _| 1.0).solve().map(|(cost, point)| (point, cost)).collect();
+        assert_eq!(reached.get(&(0, 0)), Some(&0.0));
+        assert_eq!(reached.get(&(1, 0)), Some(&1.0));
+        assert_eq!(reached.get(&(2, 0)), Some(&2.0));
+        assert_eq!(reached.get(&(0, 1)), Some(&1.0));
+        assert_eq!(reached.get(&(0, 2)), Some(&2.0));
+        assert!(!reached.contains_key(&(3, 0)));
+    }
+
+    #[test]
+    fn position_dependent_cost_charges_the_neighbor_not_the_source() {
+        let mut map = SparseTaxicabMap::new();
+        for (x, y) in [(0, 0), (1, 0), (2, 0)] {
+            map.set_point(x, y, ());
+        }
+        // Stepping onto (1, 0) is free, every other step costs 1. If the cost were (wrongly)
+        // looked up by the source position instead of the neighbor, the 0 -> 1 step would be
+        // charged by source (0, 0) (not free) and never clear a 0.5 budget at all.
+        let reached: BTreeMap<_, _> = map
+            .action_field((0, 0), 0.5)
+            .with_cost(|pos, _| if *pos == (1, 0) { 0.0 } else { 1.0 })
+            .solve()
+            .map(|(cost, point)| (point, cost))
+            .collect();
+        assert_eq!(reached.get(&(0, 0)), Some(&0.0));
+        assert_eq!(reached.get(&(1, 0)), Some(&0.0));
+        assert!(!reached.contains_key(&(2, 0)));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::TaxicabMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub mod action_field;
+
+/// A sparse manhattan map backed by a `BTreeMap`, a better choice than [`TaxicabMap`] when most
+/// of an effectively unbounded grid will be blank, since only occupied cells are stored.
+///
+/// Unlike [`TaxicabMap`] this map has no fixed width/height, so there is no period to wrap
+/// around: it has no `cycle_x`/`cycle_y` config.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct SparseTaxicabMap<T> {
+    sparse: BTreeMap<(isize, isize), T>,
+}
+
+impl<T> Default for SparseTaxicabMap<T> {
+    fn default() -> Self {
+        Self { sparse: BTreeMap::new() }
+    }
+}
+
+impl<T> SparseTaxicabMap<T> {
+    /// Create an empty sparse taxicab map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub(crate) fn from_parts(sparse: BTreeMap<(isize, isize), T>) -> Self {
+        Self { sparse }
+    }
+    /// Get the value at a point, or `None` if it is undefined.
+    pub fn get_point(&self, x: isize, y: isize) -> Option<&T> {
+        self.sparse.get(&(x, y))
+    }
+    /// Get a mutable reference to the value at a point, or `None` if it is undefined.
+    pub fn mut_point(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.sparse.get_mut(&(x, y))
+    }
+    /// Define the value at a point, overwriting it if it was already defined.
+    pub fn set_point(&mut self, x: isize, y: isize, value: T) {
+        self.sparse.insert((x, y), value);
+    }
+    /// Whether a point is defined.
+    pub fn has_point(&self, x: isize, y: isize) -> bool {
+        self.sparse.contains_key(&(x, y))
+    }
+    /// Count all defined points in the map.
+    pub fn count_points(&self) -> usize {
+        self.sparse.len()
+    }
+    /// Find at most 4 defined points adjacent to a point.
+    pub fn points_nearby(&self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.points_around(x, y, 1)
+    }
+    /// Find all defined points within a certain taxicab distance of a point.
+    pub fn points_around(&self, x: isize, y: isize, steps: usize) -> impl Iterator<Item = (isize, isize)> + '_ {
+        crate::dense_map::iters::DiamondPoints::new(x, y, steps as isize).filter(move |&(x, y)| self.has_point(x, y))
+    }
+}
+
+impl<T: Clone> SparseTaxicabMap<T> {
+    /// Convert to a dense [`TaxicabMap`], filling every undefined cell with `fill`.
+    ///
+    /// The dense map is sized to the bounding box of the defined points and its origin is set
+    /// so that absolute coordinates are preserved.
+    pub fn to_dense(&self, fill: &T) -> TaxicabMap<T> {
+        let mut keys = self.sparse.keys();
+        let (mut min_x, mut min_y) = match keys.next() {
+            Some(&(x, y)) => (x, y),
+            None => return TaxicabMap::rectangle(0, 0, fill),
+        };
+        let (mut max_x, mut max_y) = (min_x, min_y);
+        for &(x, y) in keys {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut dense = TaxicabMap::rectangle(width, height, fill).with_origin(min_x, min_y);
+        for (&(x, y), value) in self.sparse.iter() {
+            dense.set_point(x, y, value.clone());
+        }
+        dense
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_dense() {
+        let mut sparse = SparseTaxicabMap::new();
+        sparse.set_point(-2, 3, 1u32);
+        sparse.set_point(5, -1, 2u32);
+        assert_eq!(sparse.count_points(), 2);
+
+        let dense = sparse.to_dense(&0);
+        assert_eq!(dense.get_point(-2, 3), Some(&1));
+        assert_eq!(dense.get_point(5, -1), Some(&2));
+        assert_eq!(dense.get_point(0, 0), Some(&0));
+
+        let back = dense.to_sparse();
+        assert_eq!(back.get_point(-2, 3), Some(&1));
+        assert_eq!(back.get_point(5, -1), Some(&2));
+        assert_eq!(back.count_points(), dense.count_points());
+    }
+
+    #[test]
+    fn points_around_skips_undefined_cells() {
+        let mut sparse = SparseTaxicabMap::new();
+        sparse.set_point(0, 0, ());
+        sparse.set_point(1, 0, ());
+        let nearby: Vec<_> = sparse.points_nearby(0, 0).collect();
+        assert_eq!(nearby, vec![(1, 0)]);
+    }
+}